@@ -1,10 +1,28 @@
 //! Cloud KMS signing with automatic algorithm discovery.
 
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use bytes::Bytes;
-use chewie_crypto::signer::Signer;
+use chewie_crypto::cipher::{Decryptor, Encryptor};
+use chewie_crypto::signer::{Signer, Verifier};
+use ed25519_dalek::Signature as Ed25519Signature;
 use google_cloud_kms_v1::{
-    client::KeyManagementService, model::crypto_key_version::CryptoKeyVersionAlgorithm,
+    client::KeyManagementService,
+    model::crypto_key_version::CryptoKeyVersionAlgorithm,
+};
+use p256::ecdsa::{Signature as P256Signature, VerifyingKey as P256VerifyingKey};
+use p384::ecdsa::{Signature as P384Signature, VerifyingKey as P384VerifyingKey};
+use pkcs8::DecodePublicKey;
+use rsa::{
+    RsaPublicKey,
+    pkcs1v15::VerifyingKey as Pkcs1VerifyingKey,
+    pss::VerifyingKey as PssVerifyingKey,
 };
+use sha2::{Sha256, Sha384, Sha512};
+use signature::Verifier as _;
 use snafu::prelude::*;
 
 /// Errors that can occur when creating a key.
@@ -16,11 +34,24 @@ pub enum SetupError {
         /// The underlying error from the KMS API.
         source: google_cloud_kms_v1::Error,
     },
+    /// Failed to retrieve the public key.
+    GetPublicKey {
+        /// The underlying error from the KMS API.
+        source: google_cloud_kms_v1::Error,
+    },
+    /// The public key PEM returned by KMS could not be parsed.
+    InvalidPublicKey {
+        /// The underlying PKCS#8/SPKI parse error.
+        source: pkcs8::spki::Error,
+    },
     /// The specified key uses an unsupported algorithm.
     UnsupportedAlgorithm {
         /// The algorithm reported by the KMS API.
         algorithm: CryptoKeyVersionAlgorithm,
     },
+    /// Digest mode was requested for a key whose algorithm does not support
+    /// pre-hashed signing (Ed25519).
+    DigestModeUnsupported,
 }
 
 /// Errors that can occur when using a key.
@@ -32,6 +63,41 @@ pub enum SigningError {
         /// The underlying error from the KMS API.
         source: google_cloud_kms_v1::Error,
     },
+    /// Failed to serialize the JWS protected header.
+    SerializeHeader {
+        /// The underlying serialization error.
+        source: serde_json::Error,
+    },
+    /// The signature KMS returned for an ECDSA key was not valid ASN.1 DER.
+    InvalidEcdsaSignature,
+    /// A CRC32C checksum did not match, indicating possible data corruption
+    /// in transit to or from KMS.
+    IntegrityCheckFailed {
+        /// The name of the field whose checksum did not match.
+        field: &'static str,
+    },
+    /// Digest mode was requested for a key whose algorithm does not support
+    /// pre-hashed signing (Ed25519 signs the raw message only).
+    DigestModeUnsupported,
+}
+
+/// Errors that can occur when verifying a signature.
+#[derive(Debug, Snafu)]
+#[non_exhaustive]
+pub enum VerifyError {
+    /// The signature does not match the input for this key.
+    SignatureMismatch,
+}
+
+/// The hash algorithm used to pre-hash a payload for [`AsymmetricJwsKey::sign_digest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DigestAlgorithm {
+    /// SHA-256, used by `RS256`, `PS256`, and `ES256`.
+    Sha256,
+    /// SHA-384, used by `ES384`.
+    Sha384,
+    /// SHA-512, used by `RS512` and `PS512`.
+    Sha512,
 }
 
 /// Information about the algorithm supported by the key.
@@ -39,9 +105,24 @@ pub enum SigningError {
 struct AlgorithmInfo {
     /// A human readable algorithm name.
     algorithm: &'static str,
-    #[allow(dead_code)]
-    /// The JWS-compatible algorithm name.
+    /// The JWS-compatible algorithm name, as used in the `alg` JOSE header.
     jwt_alg: &'static str,
+    /// The digest algorithm to pre-hash with for `sign_digest`, or `None` if
+    /// the key's algorithm does not support pre-hashed signing (Ed25519).
+    digest_algorithm: Option<DigestAlgorithm>,
+}
+
+/// Additional fields to merge into the protected header of a JWS produced by
+/// [`AsymmetricJwsKey::sign_jws`].
+///
+/// `alg` is always set from the key's algorithm, and `kid` defaults to the
+/// key's KMS resource name unless overridden here.
+#[derive(Debug, Clone, Default)]
+pub struct JwsHeader {
+    /// Overrides the default `kid` (the key's KMS resource name).
+    pub kid: Option<String>,
+    /// Additional protected header parameters, e.g. `typ` or `cty`.
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An asymmetric key that supports JWS, stored in Google Cloud KMS.
@@ -53,11 +134,19 @@ pub struct AsymmetricJwsKey {
     resource_name: String,
     /// Information about the algorithm supported by the key.
     algorithm_info: AlgorithmInfo,
+    /// Whether `sign` verifies CRC32C checksums on the request and response.
+    verify_integrity: bool,
+    /// Whether `sign` hashes the input locally and sends a digest instead of
+    /// the raw payload.
+    digest_mode: bool,
 }
 
 impl AsymmetricJwsKey {
     /// Create a new `AsymmetricJwsKey`.
     ///
+    /// CRC32C integrity checking on `sign` is enabled by default; use
+    /// [`AsymmetricJwsKey::with_integrity_check`] to disable it.
+    ///
     /// # Errors
     ///
     /// Returns an error if the key information could not be retrieved,
@@ -67,14 +156,213 @@ impl AsymmetricJwsKey {
         resource_name: impl Into<String>,
     ) -> Result<Self, SetupError> {
         let resource_name = resource_name.into();
-        let algorithm_info = get_algorithm_info_for_resource(&kms_client, &resource_name).await?;
+        let (algorithm_info, _) =
+            get_algorithm_info_for_resource(&kms_client, &resource_name).await?;
 
         Ok(Self {
             client: kms_client,
             resource_name,
             algorithm_info,
+            verify_integrity: true,
+            digest_mode: false,
         })
     }
+
+    /// Enable or disable CRC32C integrity checking of the data and signature
+    /// sent to and received from KMS during `sign`. Enabled by default.
+    #[must_use]
+    pub fn with_integrity_check(mut self, enabled: bool) -> Self {
+        self.verify_integrity = enabled;
+        self
+    }
+
+    /// Make `sign` hash the input locally and send a digest to KMS instead of
+    /// the full payload, avoiding a round-trip of the plaintext for large
+    /// inputs. Disabled by default. Equivalent to calling
+    /// [`AsymmetricJwsKey::sign_digest`] from `sign`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetupError::DigestModeUnsupported`] if `enabled` is `true`
+    /// and the key's algorithm is Ed25519, which does not support pre-hashed
+    /// signing.
+    pub fn with_digest_mode(mut self, enabled: bool) -> Result<Self, SetupError> {
+        ensure!(
+            !enabled || self.algorithm_info.digest_algorithm.is_some(),
+            DigestModeUnsupportedSnafu
+        );
+        self.digest_mode = enabled;
+        Ok(self)
+    }
+
+    /// Fetch the PEM-encoded `SubjectPublicKeyInfo` for this key from KMS.
+    ///
+    /// This is useful for publishing a JWKS endpoint, or for constructing an
+    /// [`AsymmetricJwsVerifier`] that verifies signatures locally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the public key could not be retrieved.
+    pub async fn get_public_key(&self) -> Result<String, SetupError> {
+        let public_key = self
+            .client
+            .get_public_key()
+            .set_name(&self.resource_name)
+            .send()
+            .await
+            .context(GetPublicKeySnafu)?;
+
+        Ok(public_key.pem)
+    }
+
+    /// Sign `payload` and return a compact RFC 7515 JWS: `header.payload.signature`.
+    ///
+    /// The protected header always carries `alg` (derived from the key's
+    /// algorithm) and `kid` (the key's resource name, unless overridden via
+    /// `header_extra.kid`), plus any fields from `header_extra.extra`.
+    ///
+    /// ECDSA keys (`ES256`/`ES384`) return their signature from KMS as ASN.1
+    /// DER; this is converted to the fixed-width `r || s` concatenation that
+    /// JWS requires. RSA (`PS*`/`RS*`) and `EdDSA` signatures are used as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header could not be serialized, signing
+    /// fails, or (for ECDSA keys) KMS returned a malformed DER signature.
+    pub async fn sign_jws(
+        &self,
+        header_extra: JwsHeader,
+        payload: &[u8],
+    ) -> Result<String, SigningError> {
+        let mut header = header_extra.extra;
+        header.insert(
+            "alg".to_owned(),
+            serde_json::Value::String(self.algorithm_info.jwt_alg.to_owned()),
+        );
+        header.insert(
+            "kid".to_owned(),
+            serde_json::Value::String(
+                header_extra.kid.unwrap_or_else(|| self.resource_name.clone()),
+            ),
+        );
+
+        let header_json = serde_json::to_vec(&header).context(SerializeHeaderSnafu)?;
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header_json),
+            URL_SAFE_NO_PAD.encode(payload)
+        );
+
+        let raw_signature = self.sign(signing_input.as_bytes()).await?;
+        let jws_signature = match self.algorithm_info.jwt_alg {
+            "ES256" => ecdsa_der_to_raw_p256(&raw_signature)?,
+            "ES384" => ecdsa_der_to_raw_p384(&raw_signature)?,
+            _ => raw_signature,
+        };
+
+        Ok(format!(
+            "{signing_input}.{}",
+            URL_SAFE_NO_PAD.encode(jws_signature)
+        ))
+    }
+
+    /// Sign `input` by hashing it locally and sending only the digest to KMS,
+    /// avoiding a round-trip of the full payload for large inputs.
+    ///
+    /// The digest algorithm is chosen to match the key's algorithm (SHA-256
+    /// for `RS256`/`PS256`/`ES256`, SHA-384 for `ES384`, SHA-512 for
+    /// `RS512`/`PS512`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SigningError::DigestModeUnsupported`] if the key's algorithm
+    /// is Ed25519, which signs the raw message and does not support
+    /// pre-hashed signing. Otherwise returns an error if signing fails or a
+    /// CRC32C integrity check does not match.
+    pub async fn sign_digest(&self, input: &[u8]) -> Result<Bytes, SigningError> {
+        let digest_algorithm = self
+            .algorithm_info
+            .digest_algorithm
+            .context(DigestModeUnsupportedSnafu)?;
+        let digest = compute_digest(digest_algorithm, input);
+
+        let mut request = self
+            .client
+            .asymmetric_sign()
+            .set_name(&self.resource_name)
+            .set_digest(digest.clone());
+
+        if self.verify_integrity {
+            request = request.set_digest_crc32c(i64::from(digest_crc32c(&digest)));
+        }
+
+        let result = request.send().await.context(AsymmetricSignSnafu)?;
+
+        if self.verify_integrity {
+            ensure!(
+                result.verified_digest_crc32c,
+                IntegrityCheckFailedSnafu {
+                    field: "verified_digest_crc32c"
+                }
+            );
+            ensure!(
+                result.signature_crc32c == Some(i64::from(crc32c::crc32c(&result.signature))),
+                IntegrityCheckFailedSnafu {
+                    field: "signature_crc32c"
+                }
+            );
+            ensure!(
+                result.name == self.resource_name,
+                IntegrityCheckFailedSnafu { field: "name" }
+            );
+        }
+
+        Ok(result.signature)
+    }
+}
+
+/// Hashes `input` with `algorithm` and wraps the digest in the
+/// oneof-typed KMS `Digest` message.
+fn compute_digest(algorithm: DigestAlgorithm, input: &[u8]) -> google_cloud_kms_v1::model::Digest {
+    use google_cloud_kms_v1::model::Digest;
+    use sha2::Digest as _;
+
+    match algorithm {
+        DigestAlgorithm::Sha256 => Digest::new().set_sha256(Sha256::digest(input).to_vec()),
+        DigestAlgorithm::Sha384 => Digest::new().set_sha384(Sha384::digest(input).to_vec()),
+        DigestAlgorithm::Sha512 => Digest::new().set_sha512(Sha512::digest(input).to_vec()),
+    }
+}
+
+/// Computes the CRC32C of whichever digest variant is set on `digest`, for
+/// the `digest_crc32c` integrity-check field.
+fn digest_crc32c(digest: &google_cloud_kms_v1::model::Digest) -> u32 {
+    use google_cloud_kms_v1::model::digest::Digest as DigestOneof;
+
+    match &digest.digest {
+        Some(DigestOneof::Sha256(bytes))
+        | Some(DigestOneof::Sha384(bytes))
+        | Some(DigestOneof::Sha512(bytes)) => crc32c::crc32c(bytes),
+        None => crc32c::crc32c(&[]),
+    }
+}
+
+/// Converts an ASN.1 DER-encoded ECDSA P-256 signature into the raw,
+/// fixed-width `r || s` concatenation (32 bytes each) required by JWS ES256.
+fn ecdsa_der_to_raw_p256(der: &[u8]) -> Result<Bytes, SigningError> {
+    let signature = P256Signature::from_der(der)
+        .ok()
+        .context(InvalidEcdsaSignatureSnafu)?;
+    Ok(Bytes::copy_from_slice(&signature.to_bytes()))
+}
+
+/// Converts an ASN.1 DER-encoded ECDSA P-384 signature into the raw,
+/// fixed-width `r || s` concatenation (48 bytes each) required by JWS ES384.
+fn ecdsa_der_to_raw_p384(der: &[u8]) -> Result<Bytes, SigningError> {
+    let signature = P384Signature::from_der(der)
+        .ok()
+        .context(InvalidEcdsaSignatureSnafu)?;
+    Ok(Bytes::copy_from_slice(&signature.to_bytes()))
 }
 
 impl Signer for AsymmetricJwsKey {
@@ -85,23 +373,53 @@ impl Signer for AsymmetricJwsKey {
     }
 
     async fn sign(&self, input: &[u8]) -> Result<Bytes, Self::Error> {
-        let result = self
+        if self.digest_mode {
+            return self.sign_digest(input).await;
+        }
+
+        let mut request = self
             .client
             .asymmetric_sign()
             .set_name(&self.resource_name)
-            .set_data(input.to_vec())
-            .send()
-            .await
-            .context(AsymmetricSignSnafu)?;
+            .set_data(input.to_vec());
+
+        if self.verify_integrity {
+            request = request.set_data_crc32c(i64::from(crc32c::crc32c(input)));
+        }
+
+        let result = request.send().await.context(AsymmetricSignSnafu)?;
+
+        if self.verify_integrity {
+            ensure!(
+                result.verified_data_crc32c,
+                IntegrityCheckFailedSnafu {
+                    field: "verified_data_crc32c"
+                }
+            );
+            ensure!(
+                result.signature_crc32c == Some(i64::from(crc32c::crc32c(&result.signature))),
+                IntegrityCheckFailedSnafu {
+                    field: "signature_crc32c"
+                }
+            );
+            ensure!(
+                result.name == self.resource_name,
+                IntegrityCheckFailedSnafu { field: "name" }
+            );
+        }
 
         Ok(result.signature)
     }
 }
 
+/// Fetches the crypto key version for `resource_name` and returns both its
+/// [`AlgorithmInfo`] and the raw [`CryptoKeyVersionAlgorithm`] it was derived
+/// from, so callers that also need the raw algorithm (e.g. to parse a public
+/// key) don't have to send a second, redundant `GetCryptoKeyVersion` request.
 async fn get_algorithm_info_for_resource(
     kms_client: &KeyManagementService,
     resource_name: &str,
-) -> Result<AlgorithmInfo, SetupError> {
+) -> Result<(AlgorithmInfo, CryptoKeyVersionAlgorithm), SetupError> {
     let key_version = kms_client
         .get_crypto_key_version()
         .set_name(resource_name)
@@ -109,12 +427,12 @@ async fn get_algorithm_info_for_resource(
         .await
         .context(GetCryptoKeySnafu)?;
 
-    let algorithm =
+    let algorithm_info =
         get_algorithm_info(&key_version.algorithm).with_context(|| UnsupportedAlgorithmSnafu {
             algorithm: key_version.algorithm,
         })?;
 
-    Ok(algorithm)
+    Ok((algorithm_info, key_version.algorithm))
 }
 
 fn get_algorithm_info(algorithm: &CryptoKeyVersionAlgorithm) -> Option<AlgorithmInfo> {
@@ -129,39 +447,611 @@ fn get_algorithm_info(algorithm: &CryptoKeyVersionAlgorithm) -> Option<Algorithm
         RsaSignPss2048Sha256 | RsaSignPss3072Sha256 | RsaSignPss4096Sha256 => Some(AlgorithmInfo {
             algorithm: "RSA-PSS-SHA256",
             jwt_alg: "PS256",
+            digest_algorithm: Some(DigestAlgorithm::Sha256),
         }),
         // RSA-PSS SHA-512 variant
         RsaSignPss4096Sha512 => Some(AlgorithmInfo {
             algorithm: "RSA-PSS-SHA512",
             jwt_alg: "PS512",
+            digest_algorithm: Some(DigestAlgorithm::Sha512),
         }),
         // RSA PKCS#1 v1.5 SHA-256 variants (2048/3072/4096 bit keys)
         RsaSignPkcs12048Sha256 | RsaSignPkcs13072Sha256 | RsaSignPkcs14096Sha256 => {
             Some(AlgorithmInfo {
                 algorithm: "RSA-PKCS1-SHA256",
                 jwt_alg: "RS256",
+                digest_algorithm: Some(DigestAlgorithm::Sha256),
             })
         }
         // RSA PKCS#1 v1.5 SHA-512 variant
         RsaSignPkcs14096Sha512 => Some(AlgorithmInfo {
             algorithm: "RSA-PKCS1-SHA512",
             jwt_alg: "RS512",
+            digest_algorithm: Some(DigestAlgorithm::Sha512),
         }),
         // ECDSA P-256
         EcSignP256Sha256 => Some(AlgorithmInfo {
             algorithm: "ECDSA-P256",
             jwt_alg: "ES256",
+            digest_algorithm: Some(DigestAlgorithm::Sha256),
         }),
         // ECDSA P-384
         EcSignP384Sha384 => Some(AlgorithmInfo {
             algorithm: "ECDSA-P384",
             jwt_alg: "ES384",
+            digest_algorithm: Some(DigestAlgorithm::Sha384),
         }),
-        // EdDSA (Ed25519)
+        // EdDSA (Ed25519) signs the raw message; pre-hashed signing is not supported
         EcSignEd25519 => Some(AlgorithmInfo {
             algorithm: "EdDSA-Ed25519",
-            jwt_alg: "Ed25519",
+            jwt_alg: "EdDSA",
+            digest_algorithm: None,
         }),
         _ => None,
     }
 }
+
+/// The parsed public key material backing an [`AsymmetricJwsVerifier`].
+#[derive(Debug, Clone)]
+enum VerifyingKeyMaterial {
+    /// RSASSA-PKCS1-v1_5 with SHA-256.
+    RsaPkcs1Sha256(Pkcs1VerifyingKey<Sha256>),
+    /// RSASSA-PKCS1-v1_5 with SHA-512.
+    RsaPkcs1Sha512(Pkcs1VerifyingKey<Sha512>),
+    /// RSASSA-PSS with SHA-256.
+    RsaPssSha256(PssVerifyingKey<Sha256>),
+    /// RSASSA-PSS with SHA-512.
+    RsaPssSha512(PssVerifyingKey<Sha512>),
+    /// ECDSA on the NIST P-256 curve.
+    EcP256(P256VerifyingKey),
+    /// ECDSA on the NIST P-384 curve.
+    EcP384(P384VerifyingKey),
+    /// EdDSA on Curve25519.
+    Ed25519(ed25519_dalek::VerifyingKey),
+}
+
+/// Parses the PEM-encoded `SubjectPublicKeyInfo` returned by KMS into the
+/// key type implied by the discovered [`CryptoKeyVersionAlgorithm`].
+fn parse_verifying_key(
+    algorithm: &CryptoKeyVersionAlgorithm,
+    pem: &str,
+) -> Result<VerifyingKeyMaterial, SetupError> {
+    use CryptoKeyVersionAlgorithm::{
+        EcSignEd25519, EcSignP256Sha256, EcSignP384Sha384, RsaSignPkcs12048Sha256,
+        RsaSignPkcs13072Sha256, RsaSignPkcs14096Sha256, RsaSignPkcs14096Sha512,
+        RsaSignPss2048Sha256, RsaSignPss3072Sha256, RsaSignPss4096Sha256, RsaSignPss4096Sha512,
+    };
+
+    match algorithm {
+        RsaSignPkcs12048Sha256 | RsaSignPkcs13072Sha256 | RsaSignPkcs14096Sha256 => {
+            let key = RsaPublicKey::from_public_key_pem(pem).context(InvalidPublicKeySnafu)?;
+            Ok(VerifyingKeyMaterial::RsaPkcs1Sha256(
+                Pkcs1VerifyingKey::new(key),
+            ))
+        }
+        RsaSignPkcs14096Sha512 => {
+            let key = RsaPublicKey::from_public_key_pem(pem).context(InvalidPublicKeySnafu)?;
+            Ok(VerifyingKeyMaterial::RsaPkcs1Sha512(
+                Pkcs1VerifyingKey::new(key),
+            ))
+        }
+        RsaSignPss2048Sha256 | RsaSignPss3072Sha256 | RsaSignPss4096Sha256 => {
+            let key = RsaPublicKey::from_public_key_pem(pem).context(InvalidPublicKeySnafu)?;
+            Ok(VerifyingKeyMaterial::RsaPssSha256(PssVerifyingKey::new(
+                key,
+            )))
+        }
+        RsaSignPss4096Sha512 => {
+            let key = RsaPublicKey::from_public_key_pem(pem).context(InvalidPublicKeySnafu)?;
+            Ok(VerifyingKeyMaterial::RsaPssSha512(PssVerifyingKey::new(
+                key,
+            )))
+        }
+        EcSignP256Sha256 => {
+            let key =
+                P256VerifyingKey::from_public_key_pem(pem).context(InvalidPublicKeySnafu)?;
+            Ok(VerifyingKeyMaterial::EcP256(key))
+        }
+        EcSignP384Sha384 => {
+            let key =
+                P384VerifyingKey::from_public_key_pem(pem).context(InvalidPublicKeySnafu)?;
+            Ok(VerifyingKeyMaterial::EcP384(key))
+        }
+        EcSignEd25519 => {
+            let key = ed25519_dalek::VerifyingKey::from_public_key_pem(pem)
+                .context(InvalidPublicKeySnafu)?;
+            Ok(VerifyingKeyMaterial::Ed25519(key))
+        }
+        _ => UnsupportedAlgorithmSnafu {
+            algorithm: *algorithm,
+        }
+        .fail(),
+    }
+}
+
+/// A verifier for signatures produced by an [`AsymmetricJwsKey`].
+///
+/// Unlike [`AsymmetricJwsKey`], verification happens entirely locally against
+/// a public key fetched once at construction time, so repeated `verify` calls
+/// do not round-trip to KMS.
+#[derive(Debug, Clone)]
+pub struct AsymmetricJwsVerifier {
+    /// Information about the algorithm supported by the key.
+    algorithm_info: AlgorithmInfo,
+    /// The parsed public key material.
+    verifying_key: VerifyingKeyMaterial,
+}
+
+impl AsymmetricJwsVerifier {
+    /// Create a new `AsymmetricJwsVerifier` by fetching and caching the
+    /// public key for `resource_name` from KMS.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key information or public key could not be
+    /// retrieved, the algorithm is not supported, or the returned public key
+    /// could not be parsed.
+    pub async fn new(
+        kms_client: KeyManagementService,
+        resource_name: impl Into<String>,
+    ) -> Result<Self, SetupError> {
+        let resource_name = resource_name.into();
+        let (algorithm_info, raw_algorithm) =
+            get_algorithm_info_for_resource(&kms_client, &resource_name).await?;
+
+        let public_key = kms_client
+            .get_public_key()
+            .set_name(&resource_name)
+            .send()
+            .await
+            .context(GetPublicKeySnafu)?;
+
+        let verifying_key = parse_verifying_key(&raw_algorithm, &public_key.pem)?;
+
+        Ok(Self {
+            algorithm_info,
+            verifying_key,
+        })
+    }
+}
+
+impl Verifier for AsymmetricJwsVerifier {
+    type Error = VerifyError;
+
+    fn algorithm(&self) -> &str {
+        self.algorithm_info.algorithm
+    }
+
+    async fn verify(&self, input: &[u8], signature: &[u8]) -> Result<(), Self::Error> {
+        let valid = match &self.verifying_key {
+            VerifyingKeyMaterial::RsaPkcs1Sha256(key) => rsa::pkcs1v15::Signature::try_from(signature)
+                .is_ok_and(|sig| key.verify(input, &sig).is_ok()),
+            VerifyingKeyMaterial::RsaPkcs1Sha512(key) => rsa::pkcs1v15::Signature::try_from(signature)
+                .is_ok_and(|sig| key.verify(input, &sig).is_ok()),
+            VerifyingKeyMaterial::RsaPssSha256(key) => rsa::pss::Signature::try_from(signature)
+                .is_ok_and(|sig| key.verify(input, &sig).is_ok()),
+            VerifyingKeyMaterial::RsaPssSha512(key) => rsa::pss::Signature::try_from(signature)
+                .is_ok_and(|sig| key.verify(input, &sig).is_ok()),
+            VerifyingKeyMaterial::EcP256(key) => P256Signature::from_der(signature)
+                .is_ok_and(|sig| key.verify(input, &sig).is_ok()),
+            VerifyingKeyMaterial::EcP384(key) => P384Signature::from_der(signature)
+                .is_ok_and(|sig| key.verify(input, &sig).is_ok()),
+            VerifyingKeyMaterial::Ed25519(key) => Ed25519Signature::from_slice(signature)
+                .is_ok_and(|sig| key.verify(input, &sig).is_ok()),
+        };
+
+        ensure!(valid, SignatureMismatchSnafu);
+        Ok(())
+    }
+}
+
+/// Errors that can occur when using a [`SymmetricKey`].
+#[derive(Debug, Snafu)]
+#[non_exhaustive]
+pub enum SymmetricError {
+    /// Failed to encrypt data with the key.
+    Encrypt {
+        /// The underlying error from the KMS API.
+        source: google_cloud_kms_v1::Error,
+    },
+    /// Failed to decrypt data with the key.
+    Decrypt {
+        /// The underlying error from the KMS API.
+        source: google_cloud_kms_v1::Error,
+    },
+    /// A CRC32C checksum did not match, indicating possible data corruption
+    /// in transit to or from KMS.
+    IntegrityCheckFailed {
+        /// The name of the field whose checksum did not match.
+        field: &'static str,
+    },
+}
+
+/// A symmetric (`ENCRYPT_DECRYPT`-purpose) key stored in Google Cloud KMS.
+///
+/// Unlike [`AsymmetricJwsKey`], a symmetric key has a single, implicit
+/// algorithm (AES-256-GCM), so there is no algorithm discovery step.
+#[derive(Debug, Clone)]
+pub struct SymmetricKey {
+    /// The KMS client used for operations.
+    client: KeyManagementService,
+    /// The full resource name of the key.
+    resource_name: String,
+    /// Whether `encrypt`/`decrypt` verify CRC32C checksums on the request
+    /// and response.
+    verify_integrity: bool,
+}
+
+impl SymmetricKey {
+    /// Create a new `SymmetricKey` wrapping an `ENCRYPT_DECRYPT`-purpose KMS key.
+    ///
+    /// CRC32C integrity checking is enabled by default; use
+    /// [`SymmetricKey::with_integrity_check`] to disable it.
+    #[must_use]
+    pub fn new(kms_client: KeyManagementService, resource_name: impl Into<String>) -> Self {
+        Self {
+            client: kms_client,
+            resource_name: resource_name.into(),
+            verify_integrity: true,
+        }
+    }
+
+    /// Enable or disable CRC32C integrity checking of the plaintext,
+    /// additional authenticated data, and ciphertext sent to and received
+    /// from KMS. Enabled by default.
+    #[must_use]
+    pub fn with_integrity_check(mut self, enabled: bool) -> Self {
+        self.verify_integrity = enabled;
+        self
+    }
+
+    /// Encrypt `plaintext` with this key, additionally authenticating (but
+    /// not encrypting) `aad`. Pass an empty slice if there is no AAD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encryption fails or a CRC32C integrity check does
+    /// not match.
+    pub async fn encrypt_with_aad(
+        &self,
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Bytes, SymmetricError> {
+        let mut request = self
+            .client
+            .encrypt()
+            .set_name(&self.resource_name)
+            .set_plaintext(plaintext.to_vec());
+
+        if !aad.is_empty() {
+            request = request.set_additional_authenticated_data(aad.to_vec());
+        }
+
+        if self.verify_integrity {
+            request = request.set_plaintext_crc32c(i64::from(crc32c::crc32c(plaintext)));
+            if !aad.is_empty() {
+                let aad_crc32c = i64::from(crc32c::crc32c(aad));
+                request = request.set_additional_authenticated_data_crc32c(aad_crc32c);
+            }
+        }
+
+        let result = request.send().await.context(EncryptSnafu)?;
+
+        if self.verify_integrity {
+            ensure!(
+                result.verified_plaintext_crc32c,
+                IntegrityCheckFailedSnafu {
+                    field: "verified_plaintext_crc32c"
+                }
+            );
+            if !aad.is_empty() {
+                ensure!(
+                    result.verified_additional_authenticated_data_crc32c,
+                    IntegrityCheckFailedSnafu {
+                        field: "verified_additional_authenticated_data_crc32c"
+                    }
+                );
+            }
+            ensure!(
+                result.ciphertext_crc32c == Some(i64::from(crc32c::crc32c(&result.ciphertext))),
+                IntegrityCheckFailedSnafu {
+                    field: "ciphertext_crc32c"
+                }
+            );
+            ensure!(
+                result.name == self.resource_name,
+                IntegrityCheckFailedSnafu { field: "name" }
+            );
+        }
+
+        Ok(result.ciphertext)
+    }
+
+    /// Decrypt `ciphertext` with this key, verifying it was produced with the
+    /// same `aad` passed to [`SymmetricKey::encrypt_with_aad`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if decryption fails or a CRC32C integrity check does
+    /// not match.
+    pub async fn decrypt_with_aad(
+        &self,
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Bytes, SymmetricError> {
+        let mut request = self
+            .client
+            .decrypt()
+            .set_name(&self.resource_name)
+            .set_ciphertext(ciphertext.to_vec());
+
+        if !aad.is_empty() {
+            request = request.set_additional_authenticated_data(aad.to_vec());
+        }
+
+        if self.verify_integrity {
+            request = request.set_ciphertext_crc32c(i64::from(crc32c::crc32c(ciphertext)));
+            if !aad.is_empty() {
+                let aad_crc32c = i64::from(crc32c::crc32c(aad));
+                request = request.set_additional_authenticated_data_crc32c(aad_crc32c);
+            }
+        }
+
+        let result = request.send().await.context(DecryptSnafu)?;
+
+        if self.verify_integrity {
+            ensure!(
+                result.plaintext_crc32c == Some(i64::from(crc32c::crc32c(&result.plaintext))),
+                IntegrityCheckFailedSnafu {
+                    field: "plaintext_crc32c"
+                }
+            );
+        }
+
+        Ok(result.plaintext)
+    }
+}
+
+impl Encryptor for SymmetricKey {
+    type Error = SymmetricError;
+
+    async fn encrypt(&self, plaintext: &[u8]) -> Result<Bytes, Self::Error> {
+        self.encrypt_with_aad(plaintext, &[]).await
+    }
+}
+
+impl Decryptor for SymmetricKey {
+    type Error = SymmetricError;
+
+    async fn decrypt(&self, ciphertext: &[u8]) -> Result<Bytes, Self::Error> {
+        self.decrypt_with_aad(ciphertext, &[]).await
+    }
+}
+
+/// Errors that can occur during envelope encryption or decryption.
+#[derive(Debug, Snafu)]
+#[non_exhaustive]
+pub enum EnvelopeError {
+    /// Failed to wrap (encrypt) the data-encryption key with the KMS key.
+    WrapDek {
+        /// The underlying error from the KMS key.
+        source: SymmetricError,
+    },
+    /// Failed to unwrap (decrypt) the data-encryption key with the KMS key.
+    UnwrapDek {
+        /// The underlying error from the KMS key.
+        source: SymmetricError,
+    },
+    /// Local AES-256-GCM encryption of the payload failed.
+    Encrypt,
+    /// Local AES-256-GCM decryption of the payload failed, e.g. because the
+    /// authentication tag, key, or AAD did not match.
+    Decrypt,
+    /// The envelope ciphertext blob was truncated or malformed.
+    MalformedEnvelope,
+}
+
+/// The length in bytes of an AES-GCM nonce.
+const ENVELOPE_NONCE_LEN: usize = 12;
+
+/// Envelope encryption on top of a KMS-backed [`SymmetricKey`].
+///
+/// Generates a random AES-256 data-encryption key (DEK) per message,
+/// encrypts the payload locally with AES-256-GCM, and wraps the DEK with the
+/// KMS key so only KMS can unwrap it. This avoids sending the full payload
+/// to KMS and lets callers encrypt payloads larger than KMS's own request
+/// size limits.
+///
+/// [`EnvelopeKey::encrypt`] returns a single self-describing blob (the
+/// wrapped DEK, the nonce, and the ciphertext) that
+/// [`EnvelopeKey::decrypt`] can parse back out without any side-channel
+/// metadata.
+#[derive(Debug, Clone)]
+pub struct EnvelopeKey {
+    /// The KMS key used to wrap and unwrap the data-encryption key.
+    kek: SymmetricKey,
+}
+
+impl EnvelopeKey {
+    /// Create a new `EnvelopeKey` that wraps data-encryption keys with `kek`.
+    #[must_use]
+    pub fn new(kek: SymmetricKey) -> Self {
+        Self { kek }
+    }
+
+    /// Encrypt `plaintext`, additionally authenticating (but not encrypting)
+    /// `aad`, and return a self-describing envelope ciphertext blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data-encryption key could not be wrapped by
+    /// KMS, or local AES-256-GCM encryption fails.
+    pub async fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Bytes, EnvelopeError> {
+        let dek = Aes256Gcm::generate_key(&mut OsRng);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let cipher = Aes256Gcm::new(&dek);
+
+        let ciphertext = cipher
+            .encrypt(&nonce, Payload { msg: plaintext, aad })
+            .ok()
+            .context(EncryptSnafu)?;
+
+        let wrapped_dek = self
+            .kek
+            .encrypt_with_aad(&dek, aad)
+            .await
+            .context(WrapDekSnafu)?;
+
+        Ok(encode_envelope(&wrapped_dek, &nonce, &ciphertext))
+    }
+
+    /// Decrypt an envelope ciphertext blob produced by
+    /// [`EnvelopeKey::encrypt`], verifying it was produced with the same
+    /// `aad`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob is malformed, the data-encryption key
+    /// could not be unwrapped by KMS, or local AES-256-GCM decryption fails
+    /// (e.g. the authentication tag, key, or AAD did not match).
+    pub async fn decrypt(&self, envelope: &[u8], aad: &[u8]) -> Result<Bytes, EnvelopeError> {
+        let (wrapped_dek, nonce, ciphertext) = decode_envelope(envelope)?;
+
+        let dek = self
+            .kek
+            .decrypt_with_aad(wrapped_dek, aad)
+            .await
+            .context(UnwrapDekSnafu)?;
+        ensure!(dek.len() == 32, MalformedEnvelopeSnafu);
+        let key = Key::<Aes256Gcm>::from_slice(&dek);
+        let cipher = Aes256Gcm::new(key);
+
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad })
+            .ok()
+            .context(DecryptSnafu)?;
+
+        Ok(Bytes::from(plaintext))
+    }
+}
+
+/// Encodes an envelope ciphertext blob as `u32 BE length || wrapped_dek ||
+/// nonce || ciphertext`, so [`decode_envelope`] can parse it back out.
+fn encode_envelope(wrapped_dek: &[u8], nonce: &[u8], ciphertext: &[u8]) -> Bytes {
+    let mut blob = Vec::with_capacity(4 + wrapped_dek.len() + nonce.len() + ciphertext.len());
+    blob.extend_from_slice(&u32::try_from(wrapped_dek.len()).unwrap_or(u32::MAX).to_be_bytes());
+    blob.extend_from_slice(wrapped_dek);
+    blob.extend_from_slice(nonce);
+    blob.extend_from_slice(ciphertext);
+    Bytes::from(blob)
+}
+
+/// Parses an envelope ciphertext blob produced by [`encode_envelope`] back
+/// into its wrapped data-encryption key, nonce, and ciphertext components.
+fn decode_envelope(blob: &[u8]) -> Result<(&[u8], &[u8], &[u8]), EnvelopeError> {
+    let (len_bytes, rest) = blob.split_at_checked(4).context(MalformedEnvelopeSnafu)?;
+    let len_bytes: [u8; 4] = len_bytes.try_into().ok().context(MalformedEnvelopeSnafu)?;
+    let wrapped_dek_len = usize::try_from(u32::from_be_bytes(len_bytes))
+        .ok()
+        .context(MalformedEnvelopeSnafu)?;
+
+    let (wrapped_dek, rest) = rest
+        .split_at_checked(wrapped_dek_len)
+        .context(MalformedEnvelopeSnafu)?;
+    let (nonce, ciphertext) = rest
+        .split_at_checked(ENVELOPE_NONCE_LEN)
+        .context(MalformedEnvelopeSnafu)?;
+
+    Ok((wrapped_dek, nonce, ciphertext))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+    use super::*;
+
+    #[test]
+    fn eddsa_jwt_alg_is_a_valid_jwa_identifier() {
+        let info = get_algorithm_info(&CryptoKeyVersionAlgorithm::EcSignEd25519)
+            .expect("Ed25519 is a supported algorithm");
+        assert_eq!(info.jwt_alg, "EdDSA");
+    }
+
+    #[test]
+    fn ecdsa_der_to_raw_p256_round_trips_fixed_width_signature() {
+        let raw = [0x42u8; 64];
+        let signature = P256Signature::try_from(raw.as_slice()).expect("valid raw signature");
+        let der = signature.to_der();
+
+        let result = ecdsa_der_to_raw_p256(der.as_bytes()).expect("valid DER signature");
+
+        assert_eq!(result.as_ref(), raw.as_slice());
+    }
+
+    #[test]
+    fn ecdsa_der_to_raw_p256_rejects_malformed_der() {
+        let result = ecdsa_der_to_raw_p256(&[0x00, 0x01, 0x02]);
+        assert!(matches!(result, Err(SigningError::InvalidEcdsaSignature)));
+    }
+
+    #[test]
+    fn ecdsa_der_to_raw_p384_round_trips_fixed_width_signature() {
+        let raw = [0x7fu8; 96];
+        let signature = P384Signature::try_from(raw.as_slice()).expect("valid raw signature");
+        let der = signature.to_der();
+
+        let result = ecdsa_der_to_raw_p384(der.as_bytes()).expect("valid DER signature");
+
+        assert_eq!(result.as_ref(), raw.as_slice());
+    }
+
+    #[test]
+    fn ecdsa_der_to_raw_p384_rejects_malformed_der() {
+        let result = ecdsa_der_to_raw_p384(&[0xff]);
+        assert!(matches!(result, Err(SigningError::InvalidEcdsaSignature)));
+    }
+
+    #[test]
+    fn envelope_round_trips_through_encode_and_decode() {
+        let wrapped_dek = b"wrapped-dek-bytes".as_slice();
+        let nonce = [0x7au8; ENVELOPE_NONCE_LEN];
+        let ciphertext = b"ciphertext-bytes".as_slice();
+
+        let blob = encode_envelope(wrapped_dek, &nonce, ciphertext);
+        let (decoded_dek, decoded_nonce, decoded_ciphertext) =
+            decode_envelope(&blob).expect("well-formed envelope");
+
+        assert_eq!(decoded_dek, wrapped_dek);
+        assert_eq!(decoded_nonce, nonce);
+        assert_eq!(decoded_ciphertext, ciphertext);
+    }
+
+    #[test]
+    fn decode_envelope_rejects_truncated_length_prefix() {
+        let result = decode_envelope(&[0x00, 0x00]);
+        assert!(matches!(result, Err(EnvelopeError::MalformedEnvelope)));
+    }
+
+    #[test]
+    fn decode_envelope_rejects_wrapped_dek_length_exceeding_blob() {
+        // Claims a 100-byte wrapped DEK but the blob only has 4 bytes after
+        // the length prefix.
+        let mut blob = 100u32.to_be_bytes().to_vec();
+        blob.extend_from_slice(b"abcd");
+
+        let result = decode_envelope(&blob);
+        assert!(matches!(result, Err(EnvelopeError::MalformedEnvelope)));
+    }
+
+    #[test]
+    fn decode_envelope_rejects_missing_nonce() {
+        // Wrapped DEK length is correct, but there's nothing left for the
+        // fixed-width nonce.
+        let mut blob = 4u32.to_be_bytes().to_vec();
+        blob.extend_from_slice(b"abcd");
+
+        let result = decode_envelope(&blob);
+        assert!(matches!(result, Err(EnvelopeError::MalformedEnvelope)));
+    }
+}
@@ -1,15 +1,17 @@
 use bytes::Bytes;
 use chewie_crypto::prelude::*;
-use chewie_crypto_gcp::kms::{AsymmetricJwsKey, SetupError};
+use chewie_crypto_gcp::kms::{AsymmetricJwsKey, SetupError, SigningError};
 use google_cloud_gax::response::Response;
 use google_cloud_kms_v1::{
     client::KeyManagementService,
     model::{
         AsymmetricSignRequest, AsymmetricSignResponse, CryptoKeyVersion,
         GetCryptoKeyVersionRequest, crypto_key_version::CryptoKeyVersionAlgorithm,
+        digest::Digest as DigestOneof,
     },
 };
 use mockall::predicate::*;
+use sha2::{Digest as _, Sha256};
 
 mockall::mock! {
     #[derive(Debug)]
@@ -62,6 +64,8 @@ fn setup_asymmetric_sign_expectation(
             move |req, _| {
                 let sign_response = AsymmetricSignResponse::new()
                     .set_name(req.name)
+                    .set_signature_crc32c(i64::from(crc32c::crc32c(&signed_bytes)))
+                    .set_verified_data_crc32c(true)
                     .set_signature(signed_bytes);
                 Ok(Response::from(sign_response))
             }
@@ -114,3 +118,107 @@ async fn test_asymmetric_unsupported_algorithm_fails() -> Result<(), Box<dyn std
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_sign_digest_mode_hashes_locally() -> Result<(), Box<dyn std::error::Error>> {
+    let resource_name =
+        "projects/test/locations/us/keyRings/ring/cryptoKeys/key/cryptoKeyVersions/1";
+    let expected_signed_bytes = Bytes::from_static(b"signed digest");
+    let input = b"large payload sent as a digest instead of raw data";
+    let expected_digest = Sha256::digest(input).to_vec();
+
+    let mut mock = MockKeyManagementService::new();
+    setup_get_crypto_key_expectation(
+        &mut mock,
+        resource_name,
+        CryptoKeyVersionAlgorithm::RsaSignPkcs12048Sha256,
+    );
+
+    let expected_name = resource_name.to_owned();
+    mock.expect_asymmetric_sign()
+        .withf(move |req, _| {
+            req.name == expected_name
+                && req.data.is_empty()
+                && matches!(
+                    req.digest.as_ref().and_then(|d| d.digest.clone()),
+                    Some(DigestOneof::Sha256(bytes)) if bytes == expected_digest
+                )
+        })
+        .return_once({
+            let expected_signed_bytes = expected_signed_bytes.clone();
+            move |req, _| {
+                let sign_response = AsymmetricSignResponse::new()
+                    .set_name(req.name)
+                    .set_signature_crc32c(i64::from(crc32c::crc32c(&expected_signed_bytes)))
+                    .set_verified_digest_crc32c(true)
+                    .set_signature(expected_signed_bytes);
+                Ok(Response::from(sign_response))
+            }
+        });
+
+    let client = KeyManagementService::from_stub(mock);
+    let key = AsymmetricJwsKey::new(client, resource_name)
+        .await?
+        .with_digest_mode(true)?;
+    let signed_bytes = key.sign_digest(input).await?;
+
+    assert_eq!(signed_bytes, expected_signed_bytes);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sign_digest_mode_detects_integrity_mismatch() -> Result<(), Box<dyn std::error::Error>>
+{
+    let resource_name =
+        "projects/test/locations/us/keyRings/ring/cryptoKeys/key/cryptoKeyVersions/1";
+
+    let mut mock = MockKeyManagementService::new();
+    setup_get_crypto_key_expectation(
+        &mut mock,
+        resource_name,
+        CryptoKeyVersionAlgorithm::RsaSignPkcs12048Sha256,
+    );
+
+    mock.expect_asymmetric_sign().return_once(move |req, _| {
+        let sign_response = AsymmetricSignResponse::new()
+            .set_name(req.name)
+            .set_signature_crc32c(0)
+            .set_verified_digest_crc32c(false)
+            .set_signature(Bytes::from_static(b"signed digest"));
+        Ok(Response::from(sign_response))
+    });
+
+    let client = KeyManagementService::from_stub(mock);
+    let key = AsymmetricJwsKey::new(client, resource_name)
+        .await?
+        .with_digest_mode(true)?;
+    let result = key.sign_digest(b"payload").await;
+
+    assert!(matches!(
+        result,
+        Err(SigningError::IntegrityCheckFailed {
+            field: "verified_digest_crc32c"
+        })
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_digest_mode_rejects_ed25519_keys() -> Result<(), Box<dyn std::error::Error>> {
+    let resource_name =
+        "projects/test/locations/us/keyRings/ring/cryptoKeys/key/cryptoKeyVersions/1";
+
+    let mut mock = MockKeyManagementService::new();
+    setup_get_crypto_key_expectation(
+        &mut mock,
+        resource_name,
+        CryptoKeyVersionAlgorithm::EcSignEd25519,
+    );
+
+    let client = KeyManagementService::from_stub(mock);
+    let key = AsymmetricJwsKey::new(client, resource_name).await?;
+    let result = key.with_digest_mode(true);
+
+    assert!(matches!(result, Err(SetupError::DigestModeUnsupported)));
+    Ok(())
+}
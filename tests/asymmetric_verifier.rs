@@ -0,0 +1,115 @@
+use chewie_crypto_gcp::kms::{AsymmetricJwsVerifier, SetupError};
+use google_cloud_gax::response::Response;
+use google_cloud_kms_v1::{
+    client::KeyManagementService,
+    model::{
+        CryptoKeyVersion, GetCryptoKeyVersionRequest, GetPublicKeyRequest, PublicKey,
+        crypto_key_version::CryptoKeyVersionAlgorithm,
+    },
+};
+use mockall::predicate::*;
+
+mockall::mock! {
+    #[derive(Debug)]
+    KeyManagementService {}
+
+    impl google_cloud_kms_v1::stub::KeyManagementService for KeyManagementService {
+        async fn get_crypto_key_version(
+            &self,
+            req: GetCryptoKeyVersionRequest,
+            options: google_cloud_gax::options::RequestOptions,
+        ) -> google_cloud_gax::Result<google_cloud_gax::response::Response<CryptoKeyVersion>>;
+
+        async fn get_public_key(
+            &self,
+            req: GetPublicKeyRequest,
+            options: google_cloud_gax::options::RequestOptions,
+        ) -> google_cloud_gax::Result<google_cloud_gax::response::Response<PublicKey>>;
+    }
+}
+
+fn setup_get_crypto_key_expectation(
+    mock: &mut MockKeyManagementService,
+    resource_name: impl Into<String>,
+    algorithm: CryptoKeyVersionAlgorithm,
+) {
+    let resource_name = resource_name.into();
+
+    // `return_once` means this expectation can only satisfy a single call; if
+    // `AsymmetricJwsVerifier::new` ever regresses to issuing a second,
+    // redundant `GetCryptoKeyVersion` request, mockall panics here instead of
+    // silently double-billing the API.
+    mock.expect_get_crypto_key_version()
+        .withf(move |req, _| req.name == resource_name)
+        .return_once({
+            move |req, _| {
+                let version_response = CryptoKeyVersion::new()
+                    .set_name(req.name)
+                    .set_algorithm(algorithm);
+                Ok(Response::from(version_response))
+            }
+        });
+}
+
+fn setup_get_public_key_expectation(
+    mock: &mut MockKeyManagementService,
+    resource_name: impl Into<String>,
+    pem: impl Into<String>,
+) {
+    let resource_name = resource_name.into();
+    let pem = pem.into();
+
+    mock.expect_get_public_key()
+        .withf(move |req, _| req.name == resource_name)
+        .return_once({
+            move |req, _| {
+                let response = PublicKey::new().set_name(req.name).set_pem(pem);
+                Ok(Response::from(response))
+            }
+        });
+}
+
+#[tokio::test]
+async fn test_verifier_new_issues_single_get_crypto_key_version_request()
+-> Result<(), Box<dyn std::error::Error>> {
+    let resource_name =
+        "projects/test/locations/us/keyRings/ring/cryptoKeys/key/cryptoKeyVersions/1";
+
+    let mut mock = MockKeyManagementService::new();
+    setup_get_crypto_key_expectation(
+        &mut mock,
+        resource_name,
+        CryptoKeyVersionAlgorithm::RsaSignPkcs12048Sha256,
+    );
+    // Deliberately malformed so the test doesn't depend on generating real
+    // key material; what's under test is the number of RPCs issued, not the
+    // parse result.
+    setup_get_public_key_expectation(&mut mock, resource_name, "not a valid pem");
+
+    let client = KeyManagementService::from_stub(mock);
+    let verifier = AsymmetricJwsVerifier::new(client, resource_name).await;
+
+    assert!(matches!(verifier, Err(SetupError::InvalidPublicKey { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verifier_new_unsupported_algorithm_fails() -> Result<(), Box<dyn std::error::Error>>
+{
+    let resource_name =
+        "projects/test/locations/us/keyRings/ring/cryptoKeys/key/cryptoKeyVersions/1";
+
+    let mut mock = MockKeyManagementService::new();
+    setup_get_crypto_key_expectation(&mut mock, resource_name, CryptoKeyVersionAlgorithm::KemXwing);
+
+    let client = KeyManagementService::from_stub(mock);
+    let verifier = AsymmetricJwsVerifier::new(client, resource_name).await;
+
+    assert!(matches!(
+        verifier,
+        Err(SetupError::UnsupportedAlgorithm {
+            algorithm: CryptoKeyVersionAlgorithm::KemXwing
+        })
+    ));
+    Ok(())
+}